@@ -1,9 +1,10 @@
 use std::{
+    collections::BTreeMap,
     fs::{self, File},
     io::{self, Read, Write},
     path::PathBuf,
     process::{Command, Stdio},
-    sync::{Arc, Mutex},
+    sync::{mpsc, Arc, Mutex},
     thread,
 };
 
@@ -12,7 +13,7 @@ use chrono::Duration;
 use process_control::{ChildExt, Control};
 
 use csv::Reader;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 const CSV_FILE: &str = "LimboBugs.csv";
 const RUNS_PER_ISSUE: usize = 100;
@@ -25,6 +26,109 @@ struct IssueRow {
     commit_ids: Option<String>,
     #[serde(rename = "Opts")]
     opts: Option<String>,
+    #[serde(rename = "Good Commit")]
+    good_commit: Option<String>,
+    #[serde(rename = "Bad Commit")]
+    bad_commit: Option<String>,
+}
+
+/// One simulation invocation queued for a worker thread.
+struct WorkItem {
+    issue_id: usize,
+    run_index: usize,
+    cmd: String,
+    run_dir: PathBuf,
+    commit: String,
+    opts: String,
+}
+
+/// Returns the value passed to a `--name value` or `--name=value` CLI flag.
+fn parse_value_flag(name: &str) -> Option<String> {
+    let args: Vec<String> = std::env::args().collect();
+    for (i, arg) in args.iter().enumerate() {
+        if arg == name {
+            return args.get(i + 1).cloned();
+        }
+        if let Some(value) = arg.strip_prefix(&format!("{}=", name)) {
+            return Some(value.to_string());
+        }
+    }
+    None
+}
+
+/// Returns whether a bare boolean flag (e.g. `--bisect`) was passed on the CLI.
+fn parse_flag(name: &str) -> bool {
+    std::env::args().any(|arg| arg == name)
+}
+
+/// Parses `--jobs N` from the CLI args, falling back to the `JOBS` env var
+/// and finally to 1 (the old strictly-sequential behavior).
+fn parse_jobs() -> usize {
+    parse_value_flag("--jobs")
+        .and_then(|v| v.parse::<usize>().ok())
+        .or_else(|| std::env::var("JOBS").ok().and_then(|s| s.parse::<usize>().ok()))
+        .map(|n| n.max(1))
+        .unwrap_or(1)
+}
+
+/// Parses `--output-dir PATH` / `RESULTS_DIR`, defaulting to `results/` so
+/// a sweep's `<issue>/run_*` tree can be relocated off the repo checkout.
+fn parse_output_dir() -> PathBuf {
+    parse_value_flag("--output-dir")
+        .or_else(|| std::env::var("RESULTS_DIR").ok())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("results"))
+}
+
+/// Parses `--temp-dir PATH` / `TEMP_DIR` for scratch space the simulation's
+/// build may need, so it can be pointed at fast local disk instead of the
+/// repo checkout. `None` leaves `cargo` to use its default target dir.
+fn parse_temp_dir() -> Option<PathBuf> {
+    parse_value_flag("--temp-dir")
+        .or_else(|| std::env::var("TEMP_DIR").ok())
+        .map(PathBuf::from)
+}
+
+/// Runs `items` across a fixed pool of `jobs` worker threads, each calling
+/// `run_simulation` independently. Outcomes are funneled back through an
+/// mpsc channel so progress can still be printed in the order runs finish.
+fn run_pool(jobs: usize, items: Vec<WorkItem>, timeout_secs: u64) {
+    let queue = Arc::new(Mutex::new(items.into_iter()));
+    let (done_tx, done_rx) = mpsc::channel::<(usize, usize, RunOutcome)>();
+
+    let workers: Vec<_> = (0..jobs)
+        .map(|_| {
+            let queue = Arc::clone(&queue);
+            let done_tx = done_tx.clone();
+            thread::spawn(move || loop {
+                let item = { queue.lock().unwrap().next() };
+                let Some(item) = item else {
+                    break;
+                };
+
+                run_simulation(&item.cmd, timeout_secs, &item.run_dir);
+                let outcome = classify_run(&item.run_dir);
+                if outcome != RunOutcome::Pass {
+                    if let Err(e) = write_repro(&item.run_dir, &item.commit, &item.opts) {
+                        eprintln!(
+                            "Issue {}: Run {}: failed to write repro.sh: {}",
+                            item.issue_id, item.run_index, e
+                        );
+                    }
+                }
+                let _ = done_tx.send((item.issue_id, item.run_index, outcome));
+            })
+        })
+        .collect();
+
+    drop(done_tx);
+    for (issue_id, run_index, outcome) in done_rx {
+        println!("Issue {}: Run {} done ({})", issue_id, run_index, outcome.as_str());
+    }
+
+    for worker in workers {
+        let _ = worker.join();
+    }
 }
 
 fn checked_run(command: &str) -> io::Result<()> {
@@ -64,25 +168,17 @@ fn run_simulation(cmd: &str, timeout_secs: u64, output_dir: &PathBuf) {
         }
     };
 
-    let stdout_data = Arc::new(Mutex::new(String::new()));
-    let stderr_data = Arc::new(Mutex::new(String::new()));
-
-    let mut stdout = child.stdout.take().unwrap();
-    let mut stderr = child.stderr.take().unwrap();
-
-    let stdout_buf = Arc::clone(&stdout_data);
-    let stderr_buf = Arc::clone(&stderr_data);
+    let stdout = child.stdout.take().unwrap();
+    let stderr = child.stderr.take().unwrap();
 
-    let stdout_thread = thread::spawn(move || {
-        let mut s = String::new();
-        let _ = stdout.read_to_string(&mut s);
-        *stdout_buf.lock().unwrap() = s;
+    let stdout_thread = thread::spawn({
+        let stdout_path = stdout_path.clone();
+        move || stream_to_file(stdout, &stdout_path)
     });
 
-    let stderr_thread = thread::spawn(move || {
-        let mut s = String::new();
-        let _ = stderr.read_to_string(&mut s);
-        *stderr_buf.lock().unwrap() = s;
+    let stderr_thread = thread::spawn({
+        let stderr_path = stderr_path.clone();
+        move || stream_to_file(stderr, &stderr_path)
     });
 
     let result = child
@@ -95,13 +191,13 @@ fn run_simulation(cmd: &str, timeout_secs: u64, output_dir: &PathBuf) {
         .terminate_for_timeout()
         .wait();
 
+    // The reader threads drain to completion (the pipes close once the child
+    // exits or is killed for timeout) regardless of how `wait` resolved.
+    let _ = stdout_thread.join();
+    let _ = stderr_thread.join();
+
     match result {
         Ok(Some(status)) => {
-            let _ = stdout_thread.join();
-            let _ = stderr_thread.join();
-
-            fs::write(&stdout_path, &*stdout_data.lock().unwrap()).unwrap();
-            fs::write(&stderr_path, &*stderr_data.lock().unwrap()).unwrap();
             fs::write(
                 &exit_code_path,
                 status
@@ -113,11 +209,6 @@ fn run_simulation(cmd: &str, timeout_secs: u64, output_dir: &PathBuf) {
         }
         Ok(None) => {
             // Timeout occurred, process was killed
-            let _ = stdout_thread.join();
-            let _ = stderr_thread.join();
-
-            fs::write(&stdout_path, &*stdout_data.lock().unwrap()).unwrap();
-            fs::write(&stderr_path, &*stderr_data.lock().unwrap()).unwrap();
             fs::write(&exit_code_path, "-1 timed out").unwrap();
         }
         Err(e) => {
@@ -127,12 +218,470 @@ fn run_simulation(cmd: &str, timeout_secs: u64, output_dir: &PathBuf) {
     }
 }
 
+/// Copies `reader` to `path` in fixed-size chunks, flushing periodically so
+/// the destination stays tail-able. Bounds memory use to the buffer size
+/// regardless of how much output the child produces.
+fn stream_to_file(mut reader: impl Read, path: &PathBuf) {
+    let mut file = match File::create(path) {
+        Ok(f) => f,
+        Err(_) => return,
+    };
+
+    let mut buf = vec![0u8; 1 << 20];
+    loop {
+        let n = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(_) => break,
+        };
+
+        if file.write_all(&buf[..n]).is_err() {
+            break;
+        }
+        let _ = file.flush();
+    }
+}
+
+/// Lists the commits after `good` up to and including `bad` (exclusive of
+/// `good`, inclusive of `bad`), oldest first, so they can be binary-searched in place.
+fn rev_list_ancestry_path(good: &str, bad: &str) -> io::Result<Vec<String>> {
+    let output = Command::new("git")
+        .arg("rev-list")
+        .arg("--ancestry-path")
+        .arg("--reverse")
+        .arg(format!("{}..{}", good, bad))
+        .output()?;
+
+    if !output.status.success() {
+        return Err(io::Error::new(
+            io::ErrorKind::Other,
+            format!(
+                "git rev-list --ancestry-path {}..{} failed: {}",
+                good,
+                bad,
+                String::from_utf8_lossy(&output.stderr)
+            ),
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|l| l.trim().to_string())
+        .filter(|l| !l.is_empty())
+        .collect())
+}
+
+/// The classes a finished `run_*` directory can fall into, mirroring the
+/// pass/fail job-state modeling CI runners use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RunOutcome {
+    Pass,
+    Panic,
+    AssertionFailure,
+    Timeout,
+    SpawnError,
+}
+
+impl RunOutcome {
+    fn as_str(&self) -> &'static str {
+        match self {
+            RunOutcome::Pass => "pass",
+            RunOutcome::Panic => "panic",
+            RunOutcome::AssertionFailure => "assertion-failure",
+            RunOutcome::Timeout => "timeout",
+            RunOutcome::SpawnError => "spawn-error",
+        }
+    }
+}
+
+/// Classifies a finished `run_*` directory by inspecting its exit code and
+/// scanning stderr for panic/assertion markers.
+fn classify_run(run_dir: &PathBuf) -> RunOutcome {
+    let exit_code = fs::read_to_string(run_dir.join("exit_code.txt")).unwrap_or_default();
+    let exit_code = exit_code.trim();
+
+    if exit_code.starts_with("-1") {
+        return RunOutcome::Timeout;
+    }
+    if exit_code == "-2" {
+        return RunOutcome::SpawnError;
+    }
+
+    let stderr = fs::read_to_string(run_dir.join("stderr.txt")).unwrap_or_default();
+    if stderr.contains("assertion failed") {
+        return RunOutcome::AssertionFailure;
+    }
+    if stderr.contains("panicked") {
+        return RunOutcome::Panic;
+    }
+
+    if exit_code == "0" {
+        RunOutcome::Pass
+    } else {
+        // Nonzero exit with no recognizable marker: treat as an unlabeled crash.
+        RunOutcome::Panic
+    }
+}
+
+/// A run is considered a bisect failure if it classifies as anything but `Pass`.
+fn run_is_failure(run_dir: &PathBuf) -> bool {
+    classify_run(run_dir) != RunOutcome::Pass
+}
+
+/// A run is resumable (safe to skip) if it already recorded a terminal exit
+/// code that isn't one of the harness's own error sentinels (`-2` spawn
+/// error, `-1 ...` timeout) — those mean the run itself never completed, so
+/// it needs to be retried rather than treated as done.
+fn run_already_complete(run_dir: &PathBuf) -> bool {
+    match fs::read_to_string(run_dir.join("exit_code.txt")) {
+        Ok(code) => {
+            let code = code.trim();
+            code != "-2" && !code.starts_with("-1")
+        }
+        Err(_) => false,
+    }
+}
+
+/// Returns whether `token` looks like a seed `limbo_sim` could actually
+/// accept back via `--seed`: a decimal number, or a `0x`-prefixed hex one.
+fn looks_like_seed(token: &str) -> bool {
+    if token.is_empty() {
+        return false;
+    }
+    if let Some(hex) = token.strip_prefix("0x").or_else(|| token.strip_prefix("0X")) {
+        return !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit());
+    }
+    token.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Scans captured output for a line mentioning "seed" and pulls out the full
+/// token that follows it, e.g. `Seed: 1234567` -> `Some("1234567")`. Rejects
+/// tokens that don't look like a seed `limbo_sim` would print (so a
+/// non-decimal, non-hex match falls back to the non-reproducible path
+/// instead of silently truncating into a wrong seed).
+fn extract_seed(text: &str) -> Option<String> {
+    for line in text.lines() {
+        let lower = line.to_lowercase();
+        let Some(pos) = lower.find("seed") else {
+            continue;
+        };
+
+        // Slice the lowercased copy itself rather than indexing back into
+        // `line` by byte offset: lowercasing can change a string's byte
+        // length (e.g. "İ" -> "i̇"), so `pos` isn't guaranteed to land on a
+        // char boundary in `line`. A decimal seed is unaffected by lowercasing
+        // and a hex seed only has its letters lowercased, which keeps its value.
+        let token: String = lower[pos + "seed".len()..]
+            .chars()
+            .skip_while(|c| !c.is_ascii_alphanumeric())
+            .take_while(|c| c.is_ascii_alphanumeric())
+            .collect();
+
+        if looks_like_seed(&token) {
+            return Some(token);
+        }
+    }
+
+    None
+}
+
+/// Writes `run_dir/repro.sh`, a standalone script that checks out `commit`
+/// and re-runs just the single seed this run failed with, so a developer can
+/// iterate on a reproducing seed in seconds instead of redoing the full sweep.
+/// If no seed can be extracted, the script runs the same command without
+/// `--seed` and carries a comment marking the run as non-reproducible.
+fn write_repro(run_dir: &PathBuf, commit: &str, opts: &str) -> io::Result<()> {
+    let stdout = fs::read_to_string(run_dir.join("stdout.txt")).unwrap_or_default();
+    let stderr = fs::read_to_string(run_dir.join("stderr.txt")).unwrap_or_default();
+    let seed = extract_seed(&stdout).or_else(|| extract_seed(&stderr));
+
+    let script = match &seed {
+        Some(seed) => format!(
+            "#!/bin/sh\nset -e\ngit checkout {}\ncargo run --bin limbo_sim -- {} --seed {}\n",
+            commit, opts, seed
+        ),
+        None => format!(
+            "#!/bin/sh\n# no seed could be extracted from the captured output; this run is non-reproducible\nset -e\ngit checkout {}\ncargo run --bin limbo_sim -- {}\n",
+            commit, opts
+        ),
+    };
+
+    let repro_path = run_dir.join("repro.sh");
+    fs::write(&repro_path, script)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = fs::metadata(&repro_path)?.permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(&repro_path, perms)?;
+    }
+
+    Ok(())
+}
+
+/// Executes a previously-saved `<output_dir>/<issue>/run_<run>/repro.sh`
+/// directly, skipping the full `RUNS_PER_ISSUE`-run sweep.
+fn run_repro(issue_run: &str, output_dir: &PathBuf) -> io::Result<()> {
+    let mut parts = issue_run.splitn(2, '/');
+    let issue = parts
+        .next()
+        .filter(|s| !s.is_empty())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "expected <issue>/<run>"))?;
+    let run = parts
+        .next()
+        .filter(|s| !s.is_empty())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "expected <issue>/<run>"))?;
+
+    let repro_path = output_dir.join(issue).join(format!("run_{}", run)).join("repro.sh");
+    println!("Replaying {}", repro_path.display());
+
+    // Unlike `checked_run`, inherit stdio: the whole point of `--repro` is to
+    // let a developer watch the single reproducing seed crash/print live,
+    // not have its output discarded.
+    let status = Command::new("sh").arg(&repro_path).status()?;
+    if !status.success() {
+        return Err(io::Error::new(
+            io::ErrorKind::Other,
+            format!("repro script exited with {}", status),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks out `commit`, rebuilds, and runs the simulation up to
+/// `RUNS_PER_ISSUE` times, short-circuiting as soon as one run fails.
+/// `temp_dir`, if set, relocates the build's `CARGO_TARGET_DIR` the same way
+/// the main sweep does. Returns `(is_bad, runs_executed)`.
+fn test_commit(
+    commit: &str,
+    opts: &str,
+    scratch_dir: &PathBuf,
+    timeout_secs: u64,
+    temp_dir: &Option<PathBuf>,
+) -> io::Result<(bool, usize)> {
+    checked_run(&format!("git checkout {}", commit))?;
+    checked_run("cargo cache -a")?;
+
+    let target_dir_env = temp_dir
+        .as_ref()
+        .map(|t| format!("CARGO_TARGET_DIR={} ", t.join("target").display()))
+        .unwrap_or_default();
+
+    let commit_dir = scratch_dir.join(commit);
+    for i in 1..=RUNS_PER_ISSUE {
+        let run_dir = commit_dir.join(format!("run_{}", i));
+        let cmd = format!(
+            "{}RUST_LOG=limbo_sim=debug cargo run --bin limbo_sim -- {}",
+            target_dir_env, opts
+        );
+        run_simulation(&cmd, timeout_secs, &run_dir);
+
+        if run_is_failure(&run_dir) {
+            return Ok((true, i));
+        }
+    }
+
+    Ok((false, RUNS_PER_ISSUE))
+}
+
+/// Binary-searches the commit range `(good, bad]` for the first commit that
+/// introduces a failing simulation, writing a full bisect log to
+/// `<output_dir>/<issue>/bisect.txt`. Honors the same `--output-dir`/
+/// `--temp-dir` relocation knobs as the main sweep.
+fn run_bisect(
+    issue_id: usize,
+    good: &str,
+    bad: &str,
+    opts: &str,
+    timeout_secs: u64,
+    output_dir: &PathBuf,
+    temp_dir: &Option<PathBuf>,
+) -> io::Result<()> {
+    let issue_dir = output_dir.join(issue_id.to_string());
+    fs::create_dir_all(&issue_dir)?;
+    let scratch_dir = issue_dir.join("bisect");
+    let log_path = issue_dir.join("bisect.txt");
+    let mut log = String::new();
+
+    println!("Issue {}: bisecting {}..{}", issue_id, good, bad);
+
+    let (good_is_bad, _) = test_commit(good, opts, &scratch_dir, timeout_secs, temp_dir)?;
+    log.push_str(&format!("{} GOOD (baseline) verdict={}\n", good, if good_is_bad { "BAD" } else { "GOOD" }));
+    if good_is_bad {
+        log.push_str("ABORT: known-good commit already fails, cannot bisect\n");
+        fs::write(&log_path, &log)?;
+        return Err(io::Error::new(
+            io::ErrorKind::Other,
+            format!("Issue {}: known-good commit {} is already failing", issue_id, good),
+        ));
+    }
+
+    let (bad_is_bad, _) = test_commit(bad, opts, &scratch_dir, timeout_secs, temp_dir)?;
+    log.push_str(&format!("{} BAD (baseline) verdict={}\n", bad, if bad_is_bad { "BAD" } else { "GOOD" }));
+    if !bad_is_bad {
+        log.push_str("ABORT: known-bad commit does not fail, cannot bisect\n");
+        fs::write(&log_path, &log)?;
+        return Err(io::Error::new(
+            io::ErrorKind::Other,
+            format!("Issue {}: known-bad commit {} does not fail", issue_id, bad),
+        ));
+    }
+
+    let candidates = rev_list_ancestry_path(good, bad)?;
+    if candidates.is_empty() {
+        log.push_str("ABORT: no commits between good and bad\n");
+        fs::write(&log_path, &log)?;
+        return Err(io::Error::new(
+            io::ErrorKind::Other,
+            format!("Issue {}: no candidate commits between {} and {}", issue_id, good, bad),
+        ));
+    }
+
+    let mut lo = 0usize;
+    let mut hi = candidates.len() - 1;
+
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let commit = &candidates[mid];
+        let (is_bad, runs) = test_commit(commit, opts, &scratch_dir, timeout_secs, temp_dir)?;
+        let verdict = if is_bad { "BAD" } else { "GOOD" };
+        println!("Issue {}: {} -> {} ({} runs)", issue_id, commit, verdict, runs);
+        log.push_str(&format!("{} verdict={} runs={}\n", commit, verdict, runs));
+
+        if is_bad {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+
+    let first_bad = &candidates[lo];
+    log.push_str(&format!("RESULT: first bad commit is {}\n", first_bad));
+    fs::write(&log_path, &log)?;
+
+    println!("Issue {}: first bad commit is {}", issue_id, first_bad);
+    Ok(())
+}
+
+/// Structured per-issue rollup written to `<output_dir>/<issue>/summary.json`.
+#[derive(Debug, Serialize)]
+struct IssueSummary {
+    issue: usize,
+    commit: String,
+    total_runs: usize,
+    failures: usize,
+    failure_rate: f64,
+    counts: BTreeMap<String, usize>,
+    runs_by_class: BTreeMap<String, Vec<usize>>,
+}
+
+/// Scans `issue_dir`'s `run_*` subdirectories, classifies each outcome, and
+/// writes `summary.json` plus a row in `output_dir`'s crate-wide `summary.csv`.
+fn summarize_issue(
+    issue_id: usize,
+    commit: &str,
+    issue_dir: &PathBuf,
+    output_dir: &PathBuf,
+) -> io::Result<IssueSummary> {
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    let mut runs_by_class: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+    let mut total_runs = 0usize;
+
+    for entry in fs::read_dir(issue_dir)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        let Some(index) = name.strip_prefix("run_").and_then(|s| s.parse::<usize>().ok()) else {
+            continue;
+        };
+
+        total_runs += 1;
+        let outcome = classify_run(&entry.path());
+        *counts.entry(outcome.as_str().to_string()).or_insert(0) += 1;
+        runs_by_class
+            .entry(outcome.as_str().to_string())
+            .or_insert_with(Vec::new)
+            .push(index);
+    }
+
+    let failures = total_runs - counts.get("pass").copied().unwrap_or(0);
+    let failure_rate = if total_runs > 0 {
+        failures as f64 / total_runs as f64
+    } else {
+        0.0
+    };
+
+    let summary = IssueSummary {
+        issue: issue_id,
+        commit: commit.to_string(),
+        total_runs,
+        failures,
+        failure_rate,
+        counts,
+        runs_by_class,
+    };
+
+    let json = serde_json::to_string_pretty(&summary)
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+    fs::write(issue_dir.join("summary.json"), json)?;
+
+    append_summary_csv_row(&summary, output_dir)?;
+
+    Ok(summary)
+}
+
+/// Writes (or replaces) this issue's row in `output_dir`'s crate-wide
+/// `summary.csv`. Resuming or re-running a sweep re-summarizes the same
+/// issue, so the existing row for it is dropped before the fresh one is
+/// appended rather than duplicating it.
+fn append_summary_csv_row(summary: &IssueSummary, output_dir: &PathBuf) -> io::Result<()> {
+    const HEADER: &str = "issue,commit,total_runs,failures,failure_rate";
+    let csv_path = output_dir.join("summary.csv");
+
+    let existing = fs::read_to_string(&csv_path).unwrap_or_default();
+    let row_prefix = format!("{},", summary.issue);
+    let mut rows: Vec<&str> = existing
+        .lines()
+        .filter(|line| *line != HEADER && !line.starts_with(&row_prefix))
+        .collect();
+
+    let new_row = format!(
+        "{},{},{},{},{:.4}",
+        summary.issue, summary.commit, summary.total_runs, summary.failures, summary.failure_rate
+    );
+    rows.push(&new_row);
+
+    let mut out = String::from(HEADER);
+    out.push('\n');
+    for row in rows {
+        out.push_str(row);
+        out.push('\n');
+    }
+
+    fs::write(&csv_path, out)
+}
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let TIMEOUT_SECS: u64 = std::env::var("TIMEOUT_SECS")
         .ok()
         .and_then(|s| s.parse::<u64>().ok())
         .unwrap_or(600);
 
+    let output_dir = parse_output_dir();
+    let temp_dir = parse_temp_dir();
+
+    if let Some(issue_run) = parse_value_flag("--repro") {
+        run_repro(&issue_run, &output_dir)?;
+        return Ok(());
+    }
+
+    let jobs = parse_jobs();
+    println!("Running with {} worker job(s)", jobs);
+
+    let force = parse_flag("--force");
+
     let mut reader = Reader::from_path(CSV_FILE)?;
     let mut records = vec![];
 
@@ -141,6 +690,29 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         records.push(record);
     }
 
+    if parse_flag("--bisect") {
+        for record in records {
+            let (Some(good), Some(bad)) = (record.good_commit, record.bad_commit) else {
+                println!("Issue {}: Skipped bisect (missing good/bad commit)", record.issue);
+                continue;
+            };
+
+            if let Err(e) = run_bisect(
+                record.issue,
+                &good,
+                &bad,
+                &record.opts.unwrap_or_default(),
+                TIMEOUT_SECS,
+                &output_dir,
+                &temp_dir,
+            ) {
+                println!("Issue {}: bisect failed: {}", record.issue, e);
+            }
+        }
+
+        return Ok(());
+    }
+
     for record in records {
         let issue_id = record.issue;
         let commit = record
@@ -169,7 +741,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             continue;
         }
 
-        let issue_dir = PathBuf::from(format!("results/{}", issue_id));
+        let issue_dir = output_dir.join(issue_id.to_string());
         fs::create_dir_all(&issue_dir)?;
 
         if checked_run("cargo cache -a").is_err() {
@@ -179,14 +751,48 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
         fs::write(issue_dir.join("commit.txt"), format!("{}\n", commit_str))?;
 
-        for i in 1..=RUNS_PER_ISSUE {
-            println!("Issue {}: Run {}", issue_id, i);
-            let run_dir = issue_dir.join(format!("run_{}", i));
-            let cmd = format!(
-                "RUST_LOG=limbo_sim=debug cargo run --bin limbo_sim -- {}",
-                opts
-            );
-            run_simulation(&cmd, TIMEOUT_SECS, &run_dir);
+        let target_dir_env = temp_dir
+            .as_ref()
+            .map(|t| format!("CARGO_TARGET_DIR={} ", t.join("target").display()))
+            .unwrap_or_default();
+
+        // Checkout and cache warm-up above are serialized per issue since they mutate
+        // the shared working tree; the runs themselves are independent and can fan
+        // out across the worker pool.
+        let items: Vec<WorkItem> = (1..=RUNS_PER_ISSUE)
+            .filter_map(|i| {
+                let run_dir = issue_dir.join(format!("run_{}", i));
+                if !force && run_already_complete(&run_dir) {
+                    println!("Issue {}: Run {} already complete, skipping", issue_id, i);
+                    return None;
+                }
+
+                let cmd = format!(
+                    "{}RUST_LOG=limbo_sim=debug cargo run --bin limbo_sim -- {}",
+                    target_dir_env, opts
+                );
+                Some(WorkItem {
+                    issue_id,
+                    run_index: i,
+                    cmd,
+                    run_dir,
+                    commit: commit_str.clone(),
+                    opts: opts.clone(),
+                })
+            })
+            .collect();
+
+        run_pool(jobs, items, TIMEOUT_SECS);
+
+        match summarize_issue(issue_id, &commit_str, &issue_dir, &output_dir) {
+            Ok(summary) => println!(
+                "Issue {}: {}/{} failures ({:.1}%)",
+                issue_id,
+                summary.failures,
+                summary.total_runs,
+                summary.failure_rate * 100.0
+            ),
+            Err(e) => println!("Issue {}: failed to summarize: {}", issue_id, e),
         }
     }
 